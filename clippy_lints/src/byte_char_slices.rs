@@ -0,0 +1,89 @@
+use rustc_ast::LitKind::Byte;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+use clippy_utils::diagnostics::span_lint_and_sugg;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arrays or slices made up entirely of `u8` byte-character literals, e.g.
+    /// `[b'a', b'b', b'c']` or `&[b'h', b'i']`, and suggests the equivalent byte-string
+    /// literal, e.g. `b"abc"` or `b"hi"`.
+    ///
+    /// ### Why is this bad?
+    /// A byte-string literal is more readable, and lets the compiler store the data as a
+    /// single contiguous static instead of building the array element by element.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let greeting = [b'h', b'i'];
+    /// let greeting_ref = &[b'h', b'i'];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let greeting = b"hi";
+    /// let greeting_ref = &b"hi";
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub BYTE_CHAR_SLICES,
+    style,
+    "checks for byte character slices that could be byte string literals"
+}
+declare_lint_pass!(ByteCharSlices => [BYTE_CHAR_SLICES]);
+
+impl<'tcx> LateLintPass<'tcx> for ByteCharSlices {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // Only lint the array itself, not an enclosing `&`: `check_expr` is also called on the
+        // `AddrOf` node wrapping this array, so linting both would emit two overlapping
+        // suggestions for one expression. Replacing just `[b'a', b'b']` with `b"ab"` already
+        // preserves any surrounding `&`.
+        let ExprKind::Array(elements) = expr.kind else {
+            return;
+        };
+
+        if elements.is_empty() {
+            return;
+        }
+
+        // Byte literals are always typed `u8`, but bail if the surrounding context somehow
+        // wants something other than `[u8; N]`/`&[u8]`.
+        if !matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Array(elem_ty, _) if elem_ty.is_u8()) {
+            return;
+        }
+
+        let mut bytes = Vec::with_capacity(elements.len());
+        for element in elements {
+            let ExprKind::Lit(lit) = &element.kind else { return };
+            let Byte(b) = lit.node else { return };
+            bytes.push(b);
+        }
+
+        let mut literal = String::with_capacity(bytes.len() + 2);
+        literal.push_str("b\"");
+        for b in bytes {
+            match b {
+                b'\n' => literal.push_str("\\n"),
+                b'\t' => literal.push_str("\\t"),
+                b'\r' => literal.push_str("\\r"),
+                b'\\' => literal.push_str("\\\\"),
+                b'"' => literal.push_str("\\\""),
+                0x20..=0x7e => literal.push(b as char),
+                _ => literal.push_str(&format!("\\x{b:02x}")),
+            }
+        }
+        literal.push('"');
+
+        span_lint_and_sugg(
+            cx,
+            BYTE_CHAR_SLICES,
+            expr.span,
+            "can be more succinctly written as a byte string literal",
+            "try",
+            literal,
+            Applicability::MachineApplicable,
+        );
+    }
+}
@@ -1,20 +1,24 @@
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::{ForeignItemKind, Item, ItemKind, Node};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
-
-use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::source::snippet_with_applicability;
-use rustc_errors::Applicability;
-//use rustc_hir::{Item, ItemKind};
-use clippy_utils::ty::walk_ptrs_hir_ty;
-use if_chain::if_chain;
-use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_span::Span;
 use rustc_target::spec::abi::Abi;
 
+use clippy_utils::diagnostics::span_lint_and_note;
+
 declare_clippy_lint! {
     /// ### What it does
+    /// Checks that any aggregate type (struct, enum, or union) reachable from an `extern "C"`
+    /// function's parameters, return type, or an `extern "C"` static has a defined
+    /// representation (`#[repr(C)]` or similar), recursing into the fields of nested
+    /// aggregates.
     ///
     /// ### Why is this bad?
+    /// Without an explicit `repr`, the compiler is free to reorder, pad, or otherwise lay out
+    /// a type however it likes, which is incompatible with the stable layout C code on the
+    /// other side of the FFI boundary expects.
     ///
     /// ### Example
     /// ```rust
@@ -44,54 +48,85 @@ declare_lint_pass!(ExternWithoutRepr => [EXTERN_WITHOUT_REPR]);
 
 impl<'tcx> LateLintPass<'tcx> for ExternWithoutRepr {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
-        let msg = "Should use repr to specifing data layout when struct is used in FFI";
-        if let ItemKind::Fn(fn_sig, _, _) = &item.kind {
-            let mut app = Applicability::MaybeIncorrect;
-            let snippet = snippet_with_applicability(cx, fn_sig.span, "..", &mut app);
-            if let Some((fn_attrs, _)) = snippet.split_once("fn") {
-                if fn_attrs.contains("extern \"C\"") {
-                    for i in 0..fn_sig.decl.inputs.len() {
-                        let t = hir_ty_to_ty(cx.tcx, walk_ptrs_hir_ty(&fn_sig.decl.inputs[i]));
-                        if let Some(adt) = t.ty_adt_def() {
-                            let repr = adt.repr();
-                            if repr.packed() || repr.transparent() || repr.c() || repr.align.is_some() {
-                                continue;
-                            }
-                            let struct_span = cx.tcx.def_span(adt.did());
-                            span_lint_and_then(cx, EXTERN_WITHOUT_REPR, struct_span, msg, |_| {});
-                        }
-                    }
+        match item.kind {
+            ItemKind::Fn(..) => {
+                let fn_sig = cx.tcx.fn_sig(item.owner_id).skip_binder().skip_binder();
+                if let Abi::C { unwind: _ } = fn_sig.abi {
+                    check_fn_sig(cx, item.span, "this function signature", fn_sig);
                 }
-            }
-        }
+            },
+            ItemKind::ForeignMod { abi, items } => {
+                if let Abi::C { unwind: _ } = abi {
+                    for foreign_item_ref in items {
+                        let Node::ForeignItem(foreign_item) = cx.tcx.hir().get(foreign_item_ref.id.hir_id()) else {
+                            continue;
+                        };
 
-        if_chain! {
-            if let ItemKind::ForeignMod { abi, items } = &item.kind;
-            if let Abi::C { unwind: _ } = abi;
-            then {
-                for i in 0..items.len() {
-                    if let Some(Node::ForeignItem(f)) = cx.tcx.hir().find(items[i].id.hir_id()) {
-                        if let ForeignItemKind::Fn(decl, ..) = f.kind {
-                            for j in 0..decl.inputs.len() {
-                                let t = hir_ty_to_ty(cx.tcx, walk_ptrs_hir_ty(&decl.inputs[j]));
-                                if let Some(adt) = t.ty_adt_def() {
-                                    let repr = adt.repr();
-                                    if repr.packed()
-                                        || repr.transparent()
-                                        || repr.c()
-                                        || repr.simd()
-                                        || repr.align.is_some()
-                                    {
-                                        continue;
-                                    }
-                                    let struct_span = cx.tcx.def_span(adt.did());
-                                    span_lint_and_then(cx, EXTERN_WITHOUT_REPR, struct_span, msg, |_| {});
-                                }
-                            }
+                        match foreign_item.kind {
+                            ForeignItemKind::Fn(..) => {
+                                let fn_sig = cx.tcx.fn_sig(foreign_item.owner_id).skip_binder().skip_binder();
+                                check_fn_sig(cx, foreign_item.span, "this FFI function signature", fn_sig);
+                            },
+                            ForeignItemKind::Static(..) => {
+                                let ty = cx.tcx.type_of(foreign_item.owner_id).instantiate_identity();
+                                check_ty(cx, foreign_item.span, "this extern static", ty, &mut DefIdSet::default());
+                            },
+                            ForeignItemKind::Type => {},
                         }
                     }
                 }
-            }
+            },
+            _ => {},
         }
     }
-}
\ No newline at end of file
+}
+
+fn check_fn_sig<'tcx>(cx: &LateContext<'tcx>, boundary_span: Span, boundary: &str, fn_sig: ty::FnSig<'tcx>) {
+    let mut visited = DefIdSet::default();
+
+    for ty in fn_sig.inputs().iter().copied().chain(std::iter::once(fn_sig.output())) {
+        check_ty(cx, boundary_span, boundary, ty, &mut visited);
+    }
+}
+
+/// Recursively walks `ty`, peeling off references and raw pointers, and reports every
+/// aggregate (struct/enum/union) it reaches that doesn't have a defined representation.
+/// `visited` guards against infinite recursion through self-referential types.
+fn check_ty<'tcx>(cx: &LateContext<'tcx>, boundary_span: Span, boundary: &str, ty: Ty<'tcx>, visited: &mut DefIdSet) {
+    let mut ty = ty;
+    loop {
+        ty = match ty.kind() {
+            ty::Ref(_, inner, _) | ty::RawPtr(inner, _) => *inner,
+            _ => break,
+        };
+    }
+
+    let Some(adt) = ty.ty_adt_def() else {
+        // Primitives, raw pointers to primitives, tuples, etc. have a well-defined FFI layout
+        // (or aren't FFI-safe at all, which is `improper_ctypes`'s job to catch).
+        return;
+    };
+
+    if !visited.insert(adt.did()) {
+        return;
+    }
+
+    let repr = adt.repr();
+    if !(repr.c() || repr.transparent() || repr.packed() || repr.simd() || repr.align.is_some()) {
+        span_lint_and_note(
+            cx,
+            EXTERN_WITHOUT_REPR,
+            cx.tcx.def_span(adt.did()),
+            "type should have a defined representation before being used across an FFI boundary",
+            Some(boundary_span),
+            &format!("{boundary} reaches this type"),
+        );
+    }
+
+    for variant in adt.variants() {
+        for field in &variant.fields {
+            let field_ty = cx.tcx.type_of(field.did).instantiate_identity();
+            check_ty(cx, boundary_span, boundary, field_ty, visited);
+        }
+    }
+}
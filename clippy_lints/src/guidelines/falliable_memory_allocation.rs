@@ -0,0 +1,184 @@
+use std::ops::ControlFlow;
+
+use rustc_hir as hir;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Symbol;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{match_def_path, path_def_id};
+
+use super::FALLIABLE_MEMORY_ALLOCATION;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, alloc_fns: &DefIdSet) {
+    let ExprKind::Call(callee, args) = expr.kind else {
+        return;
+    };
+
+    let Some(did) = path_def_id(cx, callee) else {
+        return;
+    };
+
+    if !alloc_fns.contains(&did) {
+        return;
+    }
+
+    check_size_arg(cx, args);
+    check_result_is_null_checked(cx, expr);
+}
+
+/// Flags size arguments computed with an unchecked multiplication, e.g. `count * size_of::<T>()`,
+/// which can silently overflow and under-allocate.
+fn check_size_arg(cx: &LateContext<'_>, args: &[Expr<'_>]) {
+    for arg in args {
+        if let ExprKind::Binary(op, ..) = arg.kind
+            && op.node == BinOpKind::Mul
+        {
+            span_lint_and_help(
+                cx,
+                FALLIABLE_MEMORY_ALLOCATION,
+                arg.span,
+                "this allocation size is computed with an unchecked multiplication that may overflow",
+                None,
+                "compute the size with `checked_mul` and bail out on `None` instead",
+            );
+        }
+    }
+}
+
+/// Flags an allocation whose result is bound to a local and then dereferenced, passed onward, or
+/// otherwise used before any `is_null()`/`!= ptr::null()` check guards that use.
+fn check_result_is_null_checked<'tcx>(cx: &LateContext<'tcx>, call_expr: &'tcx Expr<'tcx>) {
+    let Some(local) = enclosing_local(cx, call_expr) else {
+        // Not bound to a local we can track (e.g. passed straight into another call); nothing
+        // to scan for a dominating null check.
+        return;
+    };
+    let Some(name) = local.pat.simple_ident().map(|ident| ident.name) else {
+        return;
+    };
+    let Some(block) = enclosing_block(cx, local.hir_id) else {
+        return;
+    };
+    let Some(local_idx) = block.stmts.iter().position(|stmt| stmt.hir_id == local.hir_id) else {
+        return;
+    };
+
+    let rest = block.stmts[local_idx + 1..]
+        .iter()
+        .filter_map(stmt_expr)
+        .chain(block.expr);
+
+    for e in rest {
+        if expr_checks_null(cx, e, name) {
+            // A null check dominates the first use we found; nothing to report.
+            return;
+        }
+        if expr_uses_ident(e, name) {
+            span_lint_and_help(
+                cx,
+                FALLIABLE_MEMORY_ALLOCATION,
+                call_expr.span,
+                "the pointer returned by this allocation is used without checking for `null` first",
+                None,
+                "add an `is_null()` (or `!= ptr::null()`) check before dereferencing or forwarding the pointer",
+            );
+            return;
+        }
+    }
+}
+
+fn stmt_expr<'tcx>(stmt: &hir::Stmt<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match stmt.kind {
+        hir::StmtKind::Expr(e) | hir::StmtKind::Semi(e) => Some(e),
+        hir::StmtKind::Local(_) | hir::StmtKind::Item(_) => None,
+    }
+}
+
+fn enclosing_local<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) -> Option<&'tcx hir::Local<'tcx>> {
+    let map = cx.tcx.hir();
+    let mut hir_id = expr.hir_id;
+
+    loop {
+        let parent_id = map.parent_id(hir_id);
+        if parent_id == hir_id {
+            return None;
+        }
+        match map.get(parent_id) {
+            hir::Node::Local(local) => return Some(local),
+            hir::Node::Expr(hir::Expr {
+                kind: ExprKind::Block(..),
+                ..
+            })
+            | hir::Node::Block(..) => hir_id = parent_id,
+            _ => return None,
+        }
+    }
+}
+
+fn enclosing_block<'tcx>(cx: &LateContext<'tcx>, hir_id: hir::HirId) -> Option<&'tcx hir::Block<'tcx>> {
+    let map = cx.tcx.hir();
+    let mut hir_id = hir_id;
+
+    loop {
+        let parent_id = map.parent_id(hir_id);
+        if parent_id == hir_id {
+            return None;
+        }
+        if let hir::Node::Block(block) = map.get(parent_id) {
+            return Some(block);
+        }
+        hir_id = parent_id;
+    }
+}
+
+fn is_path_ident(expr: &Expr<'_>, name: Symbol) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Path(QPath::Resolved(None, path))
+            if matches!(path.segments, [seg] if seg.ident.name == name)
+    )
+}
+
+fn is_ptr_null_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(callee, []) = expr.kind
+        && let Some(did) = path_def_id(cx, callee)
+    {
+        return match_def_path(cx, did, &["core", "ptr", "null"]) || match_def_path(cx, did, &["core", "ptr", "null_mut"]);
+    }
+    false
+}
+
+fn expr_checks_null(cx: &LateContext<'_>, expr: &Expr<'_>, name: Symbol) -> bool {
+    match expr.kind {
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name.as_str() == "is_null" => is_path_ident(recv, name),
+        ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) => {
+            (is_path_ident(lhs, name) && is_ptr_null_call(cx, rhs))
+                || (is_path_ident(rhs, name) && is_ptr_null_call(cx, lhs))
+        },
+        ExprKind::If(cond, then, els) => {
+            expr_checks_null(cx, cond, name)
+                || expr_checks_null(cx, then, name)
+                || els.is_some_and(|e| expr_checks_null(cx, e, name))
+        },
+        ExprKind::Unary(_, e) | ExprKind::DropTemps(e) => expr_checks_null(cx, e, name),
+        ExprKind::Block(block, _) => {
+            block.stmts.iter().any(|s| stmt_expr(s).is_some_and(|e| expr_checks_null(cx, e, name)))
+                || block.expr.is_some_and(|e| expr_checks_null(cx, e, name))
+        },
+        _ => false,
+    }
+}
+
+fn expr_uses_ident<'tcx>(expr: &'tcx Expr<'tcx>, name: Symbol) -> bool {
+    for_each_expr(expr, |e| {
+        if is_path_ident(e, name) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -1,6 +1,7 @@
 mod falliable_memory_allocation;
 mod mem_unsafe_functions;
 mod passing_string_to_c_functions;
+mod strlen_on_c_strings;
 mod untrusted_lib_loading;
 
 use clippy_utils::def_path_def_ids;
@@ -37,16 +38,26 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
+    /// Checks for calls to dynamic-library loading functions, such as `libc::dlopen` or
+    /// the Windows `LoadLibrary` family, whose path argument is neither a compile-time constant
+    /// string literal nor the result of a `canonicalize` call.
     ///
     /// ### Why is this bad?
+    /// Loading a library from a path that is built from untrusted input (environment
+    /// variables, command-line arguments, or other attacker-influenced data) can let an
+    /// attacker redirect the load to a malicious shared object, e.g. via a manipulated
+    /// `LD_LIBRARY_PATH`-style search.
     ///
     /// ### Example
     /// ```rust
-    /// // example code where clippy issues a warning
+    /// let dir = std::env::var("PLUGIN_DIR").unwrap();
+    /// let path = format!("{dir}/plugin.so");
+    /// let handle = unsafe { libc::dlopen(path.as_ptr().cast(), libc::RTLD_NOW) };
     /// ```
     /// Use instead:
     /// ```rust
-    /// // example code which does not raise clippy warning
+    /// let path = std::path::Path::new("/usr/lib/myapp/plugin.so").canonicalize().unwrap();
+    /// let handle = unsafe { libc::dlopen(path.to_str().unwrap().as_ptr().cast(), libc::RTLD_NOW) };
     /// ```
     #[clippy::version = "1.70.0"]
     pub UNTRUSTED_LIB_LOADING,
@@ -56,16 +67,29 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
+    /// Checks for `&str`, `String`, or `&String` arguments passed to an `extern "C"` function
+    /// (resolved from the callee's actual ABI, not by string-matching its signature) where the
+    /// corresponding C parameter is a raw `c_char` or `c_void` pointer (`*const` or `*mut`).
     ///
     /// ### Why is this bad?
+    /// Rust strings are not NUL-terminated and may contain interior NUL bytes, so handing their
+    /// raw data to C code that expects a NUL-terminated buffer is undefined behavior.
     ///
     /// ### Example
     /// ```rust
-    /// // example code where clippy issues a warning
+    /// extern "C" {
+    ///     fn puts(s: *const libc::c_char) -> libc::c_int;
+    /// }
+    /// let s = String::from("hi");
+    /// unsafe { puts(s.as_ptr().cast()) };
     /// ```
     /// Use instead:
     /// ```rust
-    /// // example code which does not raise clippy warning
+    /// extern "C" {
+    ///     fn puts(s: *const libc::c_char) -> libc::c_int;
+    /// }
+    /// let s = std::ffi::CString::new("hi").unwrap();
+    /// unsafe { puts(s.as_ptr()) };
     /// ```
     #[clippy::version = "1.70.0"]
     pub PASSING_STRING_TO_C_FUNCTIONS,
@@ -75,16 +99,29 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
+    /// Checks calls to raw allocator functions (`malloc`, `calloc`, `realloc`,
+    /// `aligned_alloc`, or configured equivalents) for two common mistakes: using the
+    /// returned pointer without first checking it for `null`, and computing the requested
+    /// size with an unchecked multiplication that can overflow.
     ///
     /// ### Why is this bad?
+    /// These allocators are fallible and return `null` on failure; dereferencing or
+    /// forwarding that pointer without checking is undefined behavior. Likewise, a size
+    /// computed by multiplying an element count by `size_of::<T>()` can silently overflow
+    /// and cause the allocator to under-allocate.
     ///
     /// ### Example
     /// ```rust
-    /// // example code where clippy issues a warning
+    /// let p = unsafe { libc::malloc(count * std::mem::size_of::<u32>()) };
+    /// unsafe { *p.cast::<u32>() = 0 };
     /// ```
     /// Use instead:
     /// ```rust
-    /// // example code which does not raise clippy warning
+    /// let size = count.checked_mul(std::mem::size_of::<u32>()).expect("size overflow");
+    /// let p = unsafe { libc::malloc(size) };
+    /// if !p.is_null() {
+    ///     unsafe { *p.cast::<u32>() = 0 };
+    /// }
     /// ```
     #[clippy::version = "1.70.0"]
     pub FALLIABLE_MEMORY_ALLOCATION,
@@ -92,17 +129,65 @@ declare_clippy_lint! {
     "memory allocation without checking arguments and result"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calling `libc::strlen` (or a configured equivalent) on a pointer obtained
+    /// from `CStr::as_ptr`/`CString::as_ptr`.
+    ///
+    /// ### Why is this bad?
+    /// The length of a `CStr`/`CString` is already known on the Rust side; round-tripping
+    /// through C's `strlen` is both slower and a foot-gun if the buffer ever contains
+    /// interior NULs.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let c_str = std::ffi::CString::new("hi").unwrap();
+    /// let len = unsafe { libc::strlen(c_str.as_ptr()) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let c_str = std::ffi::CString::new("hi").unwrap();
+    /// let len = c_str.to_bytes().len();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub STRLEN_ON_C_STRINGS,
+    nursery,
+    "calling `strlen` on a `CStr`/`CString` whose length is already known"
+}
+
 #[derive(Clone, Default)]
 pub struct GuidelineLints {
     mem_uns_fns: Vec<String>,
     mem_uns_fns_ty_ids: DefIdSet,
+    lib_loading_fns: Vec<String>,
+    lib_loading_fns_ty_ids: DefIdSet,
+    untrusted_source_fns: Vec<String>,
+    untrusted_source_fns_ty_ids: DefIdSet,
+    alloc_fns: Vec<String>,
+    alloc_fns_ty_ids: DefIdSet,
+    strlen_fns: Vec<String>,
+    strlen_fns_ty_ids: DefIdSet,
 }
 
 impl GuidelineLints {
-    pub fn new(mem_uns_fns: Vec<String>) -> Self {
+    pub fn new(
+        mem_uns_fns: Vec<String>,
+        lib_loading_fns: Vec<String>,
+        untrusted_source_fns: Vec<String>,
+        alloc_fns: Vec<String>,
+        strlen_fns: Vec<String>,
+    ) -> Self {
         Self {
             mem_uns_fns,
             mem_uns_fns_ty_ids: DefIdSet::new(),
+            lib_loading_fns,
+            lib_loading_fns_ty_ids: DefIdSet::new(),
+            untrusted_source_fns,
+            untrusted_source_fns_ty_ids: DefIdSet::new(),
+            alloc_fns,
+            alloc_fns_ty_ids: DefIdSet::new(),
+            strlen_fns,
+            strlen_fns_ty_ids: DefIdSet::new(),
         }
     }
 }
@@ -112,6 +197,7 @@ impl_lint_pass!(GuidelineLints => [
     UNTRUSTED_LIB_LOADING,
     PASSING_STRING_TO_C_FUNCTIONS,
     FALLIABLE_MEMORY_ALLOCATION,
+    STRLEN_ON_C_STRINGS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for GuidelineLints {
@@ -128,20 +214,20 @@ impl<'tcx> LateLintPass<'tcx> for GuidelineLints {
 
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
         // Resolve function names to def_ids from configuration
-        for uns_fns in &self.mem_uns_fns {
-            // Path like function names such as `libc::foo` or `aa::bb::cc::bar`,
-            // this only works with dependencies.
-            if uns_fns.contains("::") {
-                let path: Vec<&str> = uns_fns.split("::").collect();
-                for did in def_path_def_ids(cx, path.as_slice()) {
-                    self.mem_uns_fns_ty_ids.insert(did);
-                }
-            }
-            // Plain function names, then we should take its libc variant into account
-            else if let Some(did) = libc_fn_def_id(cx, uns_fns) {
-                self.mem_uns_fns_ty_ids.insert(did);
+        resolve_configured_fns(cx, &self.mem_uns_fns, &mut self.mem_uns_fns_ty_ids);
+        resolve_configured_fns(cx, &self.lib_loading_fns, &mut self.lib_loading_fns_ty_ids);
+        untrusted_lib_loading::seed_builtin_loader_fns(cx, &mut self.lib_loading_fns_ty_ids);
+        resolve_configured_fns(cx, &self.untrusted_source_fns, &mut self.untrusted_source_fns_ty_ids);
+        resolve_configured_fns(cx, &self.alloc_fns, &mut self.alloc_fns_ty_ids);
+        for name in ["malloc", "calloc", "realloc", "aligned_alloc"] {
+            if let Some(did) = libc_fn_def_id(cx, name) {
+                self.alloc_fns_ty_ids.insert(did);
             }
         }
+        resolve_configured_fns(cx, &self.strlen_fns, &mut self.strlen_fns_ty_ids);
+        if let Some(did) = libc_fn_def_id(cx, "strlen") {
+            self.strlen_fns_ty_ids.insert(did);
+        }
     }
 
     fn check_item(&mut self, _cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
@@ -150,6 +236,29 @@ impl<'tcx> LateLintPass<'tcx> for GuidelineLints {
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         mem_unsafe_functions::check(cx, expr, &self.mem_uns_fns_ty_ids);
+        untrusted_lib_loading::check(cx, expr, &self.lib_loading_fns_ty_ids, &self.untrusted_source_fns_ty_ids);
+        passing_string_to_c_functions::check(cx, expr);
+        falliable_memory_allocation::check(cx, expr, &self.alloc_fns_ty_ids);
+        strlen_on_c_strings::check(cx, expr, &self.strlen_fns_ty_ids);
+    }
+}
+
+/// Resolves a list of configured function names (either plain names, taken to be `libc`
+/// functions, or `::`-separated paths such as `my_crate::my_fn`) into their `DefId`s.
+fn resolve_configured_fns(cx: &LateContext<'_>, names: &[String], ids: &mut DefIdSet) {
+    for name in names {
+        // Path like function names such as `libc::foo` or `aa::bb::cc::bar`,
+        // this only works with dependencies.
+        if name.contains("::") {
+            let path: Vec<&str> = name.split("::").collect();
+            for did in def_path_def_ids(cx, path.as_slice()) {
+                ids.insert(did);
+            }
+        }
+        // Plain function names, then we should take its libc variant into account
+        else if let Some(did) = libc_fn_def_id(cx, name) {
+            ids.insert(did);
+        }
     }
 }
 
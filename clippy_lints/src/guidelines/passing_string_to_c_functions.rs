@@ -0,0 +1,108 @@
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+use rustc_target::spec::abi::Abi;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{match_def_path, path_def_id};
+
+use super::PASSING_STRING_TO_C_FUNCTIONS;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    let Some(pairs) = c_abi_call_args(cx, expr) else {
+        return;
+    };
+
+    for (arg, param_ty) in pairs {
+        if !is_c_char_ptr(cx, param_ty) {
+            continue;
+        }
+
+        // Look through the pointer conversion at the call site (`.as_ptr()`, possibly
+        // followed by `.cast()`/`as`) to find what the pointer actually comes from, since the
+        // argument's own type here is already a raw pointer, not `&str`/`String`.
+        let Some(receiver) = as_ptr_receiver(arg) else {
+            continue;
+        };
+
+        if is_rust_string(cx, cx.typeck_results().expr_ty(receiver)) {
+            span_lint_and_help(
+                cx,
+                PASSING_STRING_TO_C_FUNCTIONS,
+                arg.span,
+                "a Rust string passed to an extern \"C\" function is not guaranteed to be NUL-terminated",
+                None,
+                "build a `CString` first (or use a `c\"...\"` literal for a literal argument) and pass its `.as_ptr()`",
+            );
+        }
+    }
+}
+
+/// If `expr` is a call or method call to an `extern "C"` function, returns its argument
+/// expressions zipped with their corresponding parameter types, skipping the implicit `self`
+/// parameter for method calls (whose `args` doesn't include the receiver, unlike `fn_sig.inputs()`).
+fn c_abi_call_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Vec<(&'tcx Expr<'tcx>, Ty<'tcx>)>> {
+    let (did, args, skip_self) = match expr.kind {
+        ExprKind::Call(callee, args) => (path_def_id(cx, callee)?, args, 0),
+        ExprKind::MethodCall(_, _, args, _) => (cx.typeck_results().type_dependent_def_id(expr.hir_id)?, args, 1),
+        _ => return None,
+    };
+
+    let fn_sig = cx.tcx.fn_sig(did).skip_binder().skip_binder();
+
+    if let Abi::C { unwind: _ } = fn_sig.abi {
+        Some(args.iter().zip(fn_sig.inputs().iter().skip(skip_self).copied()).collect())
+    } else {
+        None
+    }
+}
+
+/// Peels off `.cast()`/`.cast_mut()`/`.cast_const()` calls and `as` casts, then returns the
+/// receiver of the `.as_ptr()`/`.as_mut_ptr()` call underneath, if any.
+fn as_ptr_receiver<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let peeled = peel_ptr_conversions(expr);
+
+    if let ExprKind::MethodCall(path, recv, [], _) = peeled.kind
+        && matches!(path.ident.name.as_str(), "as_ptr" | "as_mut_ptr")
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
+
+fn peel_ptr_conversions<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match expr.kind {
+        ExprKind::Cast(inner, _) => peel_ptr_conversions(inner),
+        ExprKind::MethodCall(path, recv, [], _)
+            if matches!(path.ident.name.as_str(), "cast" | "cast_mut" | "cast_const") =>
+        {
+            peel_ptr_conversions(recv)
+        },
+        _ => expr,
+    }
+}
+
+/// `&str`, `String`, or `&String`.
+fn is_rust_string(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let peeled = ty.peel_refs();
+    peeled.is_str() || is_type_diagnostic_item(cx, peeled, sym::String)
+}
+
+/// A raw pointer (`*const` or `*mut`) to `c_char` (`i8`/`u8`) or `c_void`.
+fn is_c_char_ptr(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    let ty::RawPtr(pointee, _) = ty.kind() else {
+        return false;
+    };
+
+    matches!(pointee.kind(), ty::Int(ty::IntTy::I8) | ty::Uint(ty::UintTy::U8)) || is_c_void(cx, *pointee)
+}
+
+/// `core::ffi::c_void` (re-exported as `std::ffi::c_void`), or its `libc::c_void` equivalent.
+fn is_c_void(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    ty.ty_adt_def().is_some_and(|adt| {
+        match_def_path(cx, adt.did(), &["core", "ffi", "c_void"]) || match_def_path(cx, adt.did(), &["libc", "c_void"])
+    })
+}
@@ -0,0 +1,57 @@
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::path_def_id;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+
+use super::STRLEN_ON_C_STRINGS;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, strlen_fns: &DefIdSet) {
+    let ExprKind::Call(callee, [arg]) = expr.kind else {
+        return;
+    };
+    let Some(did) = path_def_id(cx, callee) else {
+        return;
+    };
+    if !strlen_fns.contains(&did) {
+        return;
+    }
+
+    let ExprKind::MethodCall(path, recv, [], _) = arg.kind else {
+        return;
+    };
+    if path.ident.name.as_str() != "as_ptr" {
+        return;
+    }
+
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if !(is_type_diagnostic_item(cx, recv_ty, sym::CStr) || is_type_diagnostic_item(cx, recv_ty, sym::cstring_type)) {
+        return;
+    }
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let default_snip = "..";
+    let recv_snip = snippet(cx, recv.span, default_snip);
+
+    if recv_snip != default_snip {
+        applicability = Applicability::MachineApplicable;
+    }
+
+    // `CStr::count_bytes` would be the more direct suggestion, but it only stabilized in
+    // 1.79.0 and this lint family doesn't have an msrv bump plumbed through for it yet;
+    // `to_bytes().len()` is equivalent and available on every MSRV this lint already targets.
+    span_lint_and_sugg(
+        cx,
+        STRLEN_ON_C_STRINGS,
+        expr.span,
+        "calling `strlen` on a `CStr`/`CString` that already knows its length",
+        "try",
+        format!("{recv_snip}.to_bytes().len()"),
+        applicability,
+    );
+}
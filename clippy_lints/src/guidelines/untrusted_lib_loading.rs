@@ -0,0 +1,106 @@
+use rustc_ast::LitKind;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::{def_path_def_ids, expr_or_init, match_def_path, path_def_id};
+
+use super::UNTRUSTED_LIB_LOADING;
+
+/// Dynamic-library loaders recognized out of the box, on top of whatever the user configures
+/// via `lib-loading-fns`.
+const DEFAULT_LOADER_FNS: &[&[&str]] = &[
+    &["libc", "dlopen"],
+    &["libc", "dlmopen"],
+    &["winapi", "um", "libloaderapi", "LoadLibraryA"],
+    &["winapi", "um", "libloaderapi", "LoadLibraryW"],
+    &["winapi", "um", "libloaderapi", "LoadLibraryExW"],
+];
+
+/// Seeds `ids` with the built-in loader functions that are actually present as dependencies of
+/// the crate being linted; absent ones (e.g. `winapi` when linting a Unix-only crate) simply
+/// resolve to no `DefId`s.
+pub(super) fn seed_builtin_loader_fns(cx: &LateContext<'_>, ids: &mut DefIdSet) {
+    for path in DEFAULT_LOADER_FNS {
+        for did in def_path_def_ids(cx, path) {
+            ids.insert(did);
+        }
+    }
+}
+
+/// Checks a call expression for a dynamic-library loader whose path argument cannot be proven
+/// to come from a trusted source.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    loader_fns: &DefIdSet,
+    untrusted_source_fns: &DefIdSet,
+) {
+    let ExprKind::Call(callee, [path_arg, ..]) = expr.kind else {
+        return;
+    };
+
+    let Some(callee_id) = path_def_id(cx, callee) else {
+        return;
+    };
+
+    if !loader_fns.contains(&callee_id) {
+        return;
+    }
+
+    let source = expr_or_init(cx, path_arg);
+
+    if is_trusted_path(cx, source) {
+        return;
+    }
+
+    span_lint_and_note(
+        cx,
+        UNTRUSTED_LIB_LOADING,
+        path_arg.span,
+        "loading a dynamic library from a path that may come from an untrusted source",
+        None,
+        &format!(
+            "{} — use a hard-coded or canonicalized path instead",
+            untrusted_reason(cx, source, untrusted_source_fns)
+        ),
+    );
+}
+
+/// A path is considered trusted when it's a literal string known at compile time, or when it
+/// has already gone through `canonicalize`, which resolves it to an absolute, symlink-free path.
+/// Pointer and string conversions such as `.to_str().unwrap().as_ptr().cast()` are looked
+/// through, along with the local bindings they are applied to.
+fn is_trusted_path<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(lit) => matches!(lit.node, LitKind::Str(..) | LitKind::ByteStr(..)),
+        ExprKind::MethodCall(path, recv, ..) => match path.ident.name.as_str() {
+            "canonicalize" => true,
+            "as_ptr" | "cast" | "unwrap" | "expect" | "to_str" | "as_os_str" | "as_path" | "as_bytes" => {
+                is_trusted_path(cx, expr_or_init(cx, recv))
+            },
+            _ => false,
+        },
+        ExprKind::Call(callee, _) => path_def_id(cx, callee)
+            .is_some_and(|did| match_def_path(cx, did, &["std", "fs", "canonicalize"])),
+        _ => false,
+    }
+}
+
+/// Produces a human-readable explanation of where the untrusted path likely came from, to help
+/// the user track down the actual source of the tainted data.
+fn untrusted_reason(cx: &LateContext<'_>, expr: &Expr<'_>, untrusted_source_fns: &DefIdSet) -> &'static str {
+    if let ExprKind::Call(callee, _) = expr.kind
+        && let Some(did) = path_def_id(cx, callee)
+        && untrusted_source_fns.contains(&did)
+    {
+        return "this path is derived from a configured untrusted source (e.g. an environment variable or command-line argument)";
+    }
+
+    if matches!(expr.kind, ExprKind::Path(..)) {
+        return "this path comes from a binding whose value cannot be traced, such as a function parameter";
+    }
+
+    "this path cannot be shown to be a compile-time constant or a canonicalized path"
+}
@@ -1,19 +1,22 @@
 use rustc_ast::LitKind::{Byte, Char};
+use rustc_ast::RangeLimits;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, PatKind, RangeEnd};
+use rustc_hir::{BinOpKind, Expr, ExprKind, PatKind, RangeEnd};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{def_id::DefId, sym};
 
 use clippy_utils::{
-    diagnostics::span_lint_and_sugg, in_constant, macros::root_macro_call, meets_msrv, msrvs, source::snippet,
+    diagnostics::span_lint_and_sugg, higher::Range, in_constant, macros::root_macro_call, meets_msrv, msrvs,
+    source::snippet, SpanlessEq,
 };
 
 declare_clippy_lint! {
     /// ### What it does
     /// Suggests to use dedicated built-in methods,
-    /// `is_ascii_(lowercase|uppercase|digit)` for checking on corresponding ascii range
+    /// `is_ascii_(lowercase|uppercase|digit|hexdigit|alphanumeric|whitespace)` for checking on corresponding ascii
+    /// range
     ///
     /// ### Why is this bad?
     /// Using the built-in functions is more readable and makes it
@@ -26,6 +29,9 @@ declare_clippy_lint! {
     ///     assert!(matches!(b'X', b'A'..=b'Z'));
     ///     assert!(matches!('2', '0'..='9'));
     ///     assert!(matches!('x', 'A'..='Z' | 'a'..='z'));
+    ///     let c = 'x';
+    ///     assert!(c >= 'a' && c <= 'z');
+    ///     assert!(('a'..='z').contains(&'x'));
     /// }
     /// ```
     /// Use instead:
@@ -35,6 +41,9 @@ declare_clippy_lint! {
     ///     assert!(b'X'.is_ascii_uppercase());
     ///     assert!('2'.is_ascii_digit());
     ///     assert!('x'.is_ascii_alphabetic());
+    ///     let c = 'x';
+    ///     assert!(c.is_ascii_lowercase());
+    ///     assert!('x'.is_ascii_lowercase());
     /// }
     /// ```
     #[clippy::version = "1.66.0"]
@@ -55,7 +64,7 @@ impl ManualIsAsciiCheck {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum CharRange {
     /// 'a'..='z' | b'a'..=b'z'
     LowerChar,
@@ -63,8 +72,28 @@ enum CharRange {
     UpperChar,
     /// AsciiLower | AsciiUpper
     FullChar,
-    /// '0..=9'
+    /// '0'..='9'
     Digit,
+    /// 'a'..='f' | b'a'..=b'f'
+    LowerHex,
+    /// 'A'..='F' | b'A'..=b'F'
+    UpperHex,
+    /// Digit | LowerHex | UpperHex
+    HexDigit,
+    /// FullChar | Digit
+    AlphaNumeric,
+    /// ' '
+    WhitespaceSpace,
+    /// '\t'
+    WhitespaceTab,
+    /// '\n'
+    WhitespaceNewline,
+    /// '\r'
+    WhitespaceCr,
+    /// '\x0c'
+    WhitespaceFormFeed,
+    /// WhitespaceSpace | WhitespaceTab | WhitespaceNewline | WhitespaceCr | WhitespaceFormFeed
+    Whitespace,
     Otherwise,
 }
 
@@ -78,58 +107,169 @@ impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
             return;
         }
 
-        let Some(macro_call) = root_macro_call(expr.span) else { return };
-
-        if is_matches_macro(cx, macro_call.def_id) {
-            if let ExprKind::Match(recv, [arm, ..], _) = expr.kind {
+        if let Some(macro_call) = root_macro_call(expr.span) {
+            if is_matches_macro(cx, macro_call.def_id)
+                && let ExprKind::Match(recv, [arm, ..], _) = expr.kind
+            {
                 let range = check_pat(&arm.pat.kind);
+                suggest(cx, macro_call.span, recv.span, range);
+            }
+            return;
+        }
 
-                if let Some(sugg) = match range {
-                    CharRange::UpperChar => Some("is_ascii_uppercase"),
-                    CharRange::LowerChar => Some("is_ascii_lowercase"),
-                    CharRange::FullChar => Some("is_ascii_alphabetic"),
-                    CharRange::Digit => Some("is_ascii_digit"),
-                    CharRange::Otherwise => None,
-                } {
-                    let mut applicability = Applicability::MaybeIncorrect;
-                    let default_snip = "..";
-                    // `snippet_with_applicability` may set applicability to `MaybeIncorrect` for
-                    // macro span, so we check applicability manually by comaring `recv` is not default.
-                    let recv = snippet(cx, recv.span, default_snip);
-
-                    if recv != default_snip {
-                        applicability = Applicability::MachineApplicable;
+        match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => {
+                if let Some((recv, range)) = check_and_binary(cx, lhs, rhs) {
+                    suggest(cx, expr.span, recv.span, range);
+                }
+            },
+            ExprKind::MethodCall(path, recv, [arg], _) if path.ident.name == sym::contains => {
+                if let Some(range) = Range::hir(recv)
+                    && let (Some(start), Some(end)) = (range.start, range.end)
+                    && range.limits == RangeLimits::Closed
+                {
+                    let char_range = check_range(start, end);
+                    if char_range != CharRange::Otherwise {
+                        let recv_span = if let ExprKind::AddrOf(_, _, inner) = arg.kind {
+                            inner.span
+                        } else {
+                            arg.span
+                        };
+                        suggest(cx, expr.span, recv_span, char_range);
                     }
-
-                    span_lint_and_sugg(
-                        cx,
-                        MANUAL_IS_ASCII_CHECK,
-                        macro_call.span,
-                        "manual check for common ascii range",
-                        "try",
-                        format!("{recv}.{sugg}()"),
-                        applicability,
-                    );
                 }
-            }
+            },
+            _ => {},
         }
     }
 
     extract_msrv_attr!(LateContext);
 }
 
+fn suggest(cx: &LateContext<'_>, span: rustc_span::Span, recv_span: rustc_span::Span, range: CharRange) {
+    let Some(sugg) = (match range {
+        CharRange::UpperChar => Some("is_ascii_uppercase"),
+        CharRange::LowerChar => Some("is_ascii_lowercase"),
+        CharRange::FullChar => Some("is_ascii_alphabetic"),
+        CharRange::Digit => Some("is_ascii_digit"),
+        CharRange::HexDigit => Some("is_ascii_hexdigit"),
+        CharRange::AlphaNumeric => Some("is_ascii_alphanumeric"),
+        CharRange::Whitespace => Some("is_ascii_whitespace"),
+        // Individual whitespace-set members never get a suggestion on their own — only the
+        // fully composed `Whitespace` class (see `fold_ranges`) means `is_ascii_whitespace`.
+        CharRange::LowerHex
+        | CharRange::UpperHex
+        | CharRange::WhitespaceSpace
+        | CharRange::WhitespaceTab
+        | CharRange::WhitespaceNewline
+        | CharRange::WhitespaceCr
+        | CharRange::WhitespaceFormFeed
+        | CharRange::Otherwise => None,
+    }) else {
+        return;
+    };
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let default_snip = "..";
+    // `snippet_with_applicability` may set applicability to `MaybeIncorrect` for
+    // macro span, so we check applicability manually by comaring `recv` is not default.
+    let recv = snippet(cx, recv_span, default_snip);
+
+    if recv != default_snip {
+        applicability = Applicability::MachineApplicable;
+    }
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_IS_ASCII_CHECK,
+        span,
+        "manual check for common ascii range",
+        "try",
+        format!("{recv}.{sugg}()"),
+        applicability,
+    );
+}
+
+/// Normalizes a single comparison expression such as `c >= 'a'` or `'a' <= c` into
+/// `(receiver, bound, is_lower_bound)`, where `is_lower_bound` is `true` when `bound` is the
+/// lower end of the range being checked.
+fn normalize_bound<'hir>(expr: &'hir Expr<'hir>) -> Option<(&'hir Expr<'hir>, &'hir Expr<'hir>, bool)> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    let is_lit = |e: &Expr<'_>| matches!(e.kind, ExprKind::Lit(_));
+
+    match (op.node, is_lit(lhs), is_lit(rhs)) {
+        (BinOpKind::Ge, false, true) => Some((lhs, rhs, true)),
+        (BinOpKind::Ge, true, false) => Some((rhs, lhs, false)),
+        (BinOpKind::Le, false, true) => Some((lhs, rhs, false)),
+        (BinOpKind::Le, true, false) => Some((rhs, lhs, true)),
+        _ => None,
+    }
+}
+
+/// Checks for `c >= 'a' && c <= 'z'`-like expressions (in any operand order), confirming both
+/// sides reference the same receiver before feeding the bound literals into `check_range`.
+fn check_and_binary<'tcx>(
+    cx: &LateContext<'tcx>,
+    lhs: &'tcx Expr<'tcx>,
+    rhs: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, CharRange)> {
+    let (recv_l, bound_l, is_lower_l) = normalize_bound(lhs)?;
+    let (recv_r, bound_r, is_lower_r) = normalize_bound(rhs)?;
+
+    if is_lower_l == is_lower_r || !SpanlessEq::new(cx).eq_expr(recv_l, recv_r) {
+        return None;
+    }
+
+    let (start, end) = if is_lower_l { (bound_l, bound_r) } else { (bound_r, bound_l) };
+    let range = check_range(start, end);
+
+    if range == CharRange::Otherwise {
+        None
+    } else {
+        Some((recv_l, range))
+    }
+}
+
 fn check_pat(pat_kind: &PatKind<'_>) -> CharRange {
     match pat_kind {
-        PatKind::Or(pats) => {
-            let ranges = pats.iter().map(|p| check_pat(&p.kind)).collect::<Vec<_>>();
-
-            if ranges.len() == 2 && ranges.contains(&CharRange::UpperChar) && ranges.contains(&CharRange::LowerChar) {
-                CharRange::FullChar
-            } else {
-                CharRange::Otherwise
-            }
-        },
+        PatKind::Or(pats) => fold_ranges(pats.iter().map(|p| check_pat(&p.kind)).collect()),
         PatKind::Range(Some(start), Some(end), kind) if *kind == RangeEnd::Included => check_range(start, end),
+        PatKind::Lit(lit_expr) => check_whitespace_lit(lit_expr),
+        _ => CharRange::Otherwise,
+    }
+}
+
+/// Folds a set of `CharRange`s produced by the arms of an `Or` pattern into the range they
+/// compose, e.g. `{UpperChar, LowerChar} -> FullChar` or `{Digit, LowerHex, UpperHex} -> HexDigit`.
+fn fold_ranges(ranges: Vec<CharRange>) -> CharRange {
+    let mut set = std::collections::BTreeSet::new();
+
+    for range in ranges {
+        if range == CharRange::Otherwise {
+            return CharRange::Otherwise;
+        }
+        set.insert(range);
+    }
+
+    let members: Vec<_> = set.into_iter().collect();
+
+    match members.as_slice() {
+        [single] => *single,
+        [CharRange::LowerChar, CharRange::UpperChar] => CharRange::FullChar,
+        [CharRange::LowerChar, CharRange::UpperChar, CharRange::Digit] => CharRange::AlphaNumeric,
+        [CharRange::FullChar, CharRange::Digit] => CharRange::AlphaNumeric,
+        [CharRange::Digit, CharRange::LowerHex, CharRange::UpperHex] => CharRange::HexDigit,
+        // Only the full five-member set composes into the whitespace class; any subset stays
+        // `Otherwise` so e.g. `matches!(c, ' ' | '\t')` doesn't get an incorrect suggestion.
+        [
+            CharRange::WhitespaceSpace,
+            CharRange::WhitespaceTab,
+            CharRange::WhitespaceNewline,
+            CharRange::WhitespaceCr,
+            CharRange::WhitespaceFormFeed,
+        ] => CharRange::Whitespace,
         _ => CharRange::Otherwise,
     }
 }
@@ -141,6 +281,27 @@ fn check_range(start: &Expr<'_>, end: &Expr<'_>) -> CharRange {
             (Char('a'), Char('z')) | (Byte(b'a'), Byte(b'z')) => CharRange::LowerChar,
             (Char('A'), Char('Z')) | (Byte(b'A'), Byte(b'Z')) => CharRange::UpperChar,
             (Char('0'), Char('9')) | (Byte(b'0'), Byte(b'9')) => CharRange::Digit,
+            (Char('a'), Char('f')) | (Byte(b'a'), Byte(b'f')) => CharRange::LowerHex,
+            (Char('A'), Char('F')) | (Byte(b'A'), Byte(b'F')) => CharRange::UpperHex,
+            _ => CharRange::Otherwise,
+        }
+    } else {
+        CharRange::Otherwise
+    }
+}
+
+/// Recognizes a single literal arm of the ascii-whitespace set, used as part of an `Or` pattern
+/// such as `' ' | '\t' | '\n' | '\r' | '\x0c'`. Each char maps to its own `CharRange` member so
+/// that `fold_ranges` only suggests `is_ascii_whitespace` once *all five* are present together —
+/// a lone literal like `matches!(c, '\n')` must not be treated as the whole whitespace class.
+fn check_whitespace_lit(expr: &Expr<'_>) -> CharRange {
+    if let ExprKind::Lit(lit) = &expr.kind {
+        match lit.node {
+            Char(' ') | Byte(b' ') => CharRange::WhitespaceSpace,
+            Char('\t') | Byte(b'\t') => CharRange::WhitespaceTab,
+            Char('\n') | Byte(b'\n') => CharRange::WhitespaceNewline,
+            Char('\r') | Byte(b'\r') => CharRange::WhitespaceCr,
+            Char('\x0c') | Byte(0x0c) => CharRange::WhitespaceFormFeed,
             _ => CharRange::Otherwise,
         }
     } else {